@@ -1,7 +1,26 @@
 #![expect(missing_docs)]
 
 fn main() {
-    let rustc_version = rustc_version::version().unwrap();
-    println!("cargo::rustc-env=RUSTC_VERSION={rustc_version}");
+    let version_meta = rustc_version::version_meta().unwrap();
+    println!("cargo::rustc-env=RUSTC_VERSION={}", version_meta.semver);
+
+    let channel = match version_meta.channel {
+        rustc_version::Channel::Stable => "stable",
+        rustc_version::Channel::Beta => "beta",
+        rustc_version::Channel::Nightly => "nightly",
+        rustc_version::Channel::Dev => "dev",
+    };
+    println!("cargo::rustc-env=RUSTC_CHANNEL={channel}");
+
+    if let Some(commit_hash) = &version_meta.commit_hash {
+        println!("cargo::rustc-env=RUSTC_COMMIT_HASH={commit_hash}");
+    }
+    if let Some(commit_date) = &version_meta.commit_date {
+        println!("cargo::rustc-env=RUSTC_COMMIT_DATE={commit_date}");
+    }
+
+    let target = std::env::var("TARGET").expect("cargo did not set TARGET");
+    println!("cargo::rustc-env=TARGET={target}");
+
     println!("cargo::rerun-if-changed=build.rs");
 }
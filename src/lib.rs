@@ -20,6 +20,53 @@ pub struct RawInfo {
     pub git_version: &'static str,
     /// Rustc version used to compile the program
     pub rustc_version: &'static str,
+    /// Rustc release channel, set by `build.rs` via [`rustc_version::version_meta`]
+    pub channel: &'static str,
+    /// Rustc commit hash, set by `build.rs` via [`rustc_version::version_meta`]
+    pub commit_hash: Option<&'static str>,
+    /// Rustc commit date, set by `build.rs` via [`rustc_version::version_meta`]
+    pub commit_date: Option<&'static str>,
+    /// Target triple the program was compiled for, from the env var cargo sets `TARGET`
+    pub target: &'static str,
+}
+
+/// Rustc release channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Channel {
+    /// Stable channel
+    Stable,
+    /// Beta channel
+    Beta,
+    /// Nightly channel
+    Nightly,
+    /// Dev channel, rustc built from a local checkout
+    Dev,
+}
+
+impl std::str::FromStr for Channel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "stable" => Ok(Self::Stable),
+            "beta" => Ok(Self::Beta),
+            "nightly" => Ok(Self::Nightly),
+            "dev" => Ok(Self::Dev),
+            _ => Err(format!("unknown rustc channel `{s}`")),
+        }
+    }
+}
+
+impl std::fmt::Display for Channel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Stable => "stable",
+            Self::Beta => "beta",
+            Self::Nightly => "nightly",
+            Self::Dev => "dev",
+        })
+    }
 }
 
 /// The collection of information
@@ -32,6 +79,14 @@ pub struct Info {
     pub git_version: Cow<'static, str>,
     /// Rustc version used to compile the program
     pub rustc_version: semver::Version,
+    /// Rustc release channel used to compile the program
+    pub channel: Channel,
+    /// Rustc commit hash used to compile the program
+    pub commit_hash: Option<Cow<'static, str>>,
+    /// Rustc commit date used to compile the program
+    pub commit_date: Option<Cow<'static, str>>,
+    /// Target triple the program was compiled for, e.g. `x86_64-unknown-linux-gnu`
+    pub target: Cow<'static, str>,
     /// Runtime information about the current operating system
     pub os: os_info::Info,
 }
@@ -50,15 +105,51 @@ impl Info {
     /// ```
     ///
     /// # Panics
-    /// Panics if `version` does not parse as semver or `rustc_version` does not parse as semver
+    /// Panics if `version` does not parse as semver, `rustc_version` does not parse as semver or
+    /// `channel` is not a recognised rustc release channel
     pub fn new(raw: RawInfo) -> Self {
         Self {
             cargo_pkg_version: raw.cargo_pkg_version.parse().unwrap(),
             git_version: Cow::Borrowed(raw.git_version),
             rustc_version: raw.rustc_version.parse().unwrap(),
+            channel: raw.channel.parse().unwrap(),
+            commit_hash: raw.commit_hash.map(Cow::Borrowed),
+            commit_date: raw.commit_date.map(Cow::Borrowed),
+            target: Cow::Borrowed(raw.target),
             os: os_info::get(),
         }
     }
+
+    /// Check whether the rustc version that compiled this binary satisfies a minimum supported
+    /// Rust version (MSRV).
+    ///
+    /// `msrv` is turned into a caret requirement (e.g. `1.70.0` becomes `^1.70.0`), and any
+    /// pre-release or build metadata on [`Self::rustc_version`] is stripped before matching, so a
+    /// `1.75.0-nightly` compiler still satisfies a `1.74.0` MSRV.
+    pub fn rustc_satisfies(&self, msrv: &semver::Version) -> bool {
+        let req = semver::VersionReq::parse(&format!("^{msrv}")).unwrap();
+        let clean = semver::Version::new(
+            self.rustc_version.major,
+            self.rustc_version.minor,
+            self.rustc_version.patch,
+        );
+        req.matches(&clean)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Info {
+    /// Serialize this [`Info`] to a JSON string.
+    ///
+    /// Where [`Display`](std::fmt::Display) renders a single human-readable line, this produces a
+    /// stable keyed object (`cargo_pkg_version`, `git_version`, `rustc_version`, `channel`,
+    /// `target`, `os`, ...) for machine consumers such as CI annotations or crash reporters.
+    ///
+    /// # Panics
+    /// Panics if serialization fails
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
 }
 
 impl std::fmt::Display for Info {
@@ -68,9 +159,13 @@ impl std::fmt::Display for Info {
             git_version,
             os,
             rustc_version,
+            channel,
+            commit_hash: _,
+            commit_date: _,
+            target,
         } = self;
         f.write_fmt(format_args!(
-            "{cargo_pkg_version} {git_version} rustc-{rustc_version} {os}"
+            "{cargo_pkg_version} {git_version} rustc-{rustc_version}-{channel} {target} {os}"
         ))
     }
 }
@@ -79,6 +174,13 @@ impl std::fmt::Display for Info {
 pub use git_version;
 
 /// Get [`RawInfo`] for the current pkg
+///
+/// # Requires a `build.rs`
+/// This macro reads env vars that this crate's `build.rs` sets only for this crate via
+/// `cargo::rustc-env=...` (`RUSTC_VERSION`, `RUSTC_CHANNEL`, `RUSTC_COMMIT_HASH`,
+/// `RUSTC_COMMIT_DATE`, `TARGET`) — these do not propagate to downstream crates. If you call
+/// `raw_info!()` from your own crate, copy the logic from this crate's `build.rs` into your own
+/// so the env vars are set for your crate too.
 #[macro_export]
 macro_rules! raw_info {
     () => {
@@ -86,11 +188,15 @@ macro_rules! raw_info {
             cargo_pkg_version: env!("CARGO_PKG_VERSION"),
             git_version: $crate::git_version::git_version!(fallback = "unknown"),
             rustc_version: env!("RUSTC_VERSION"),
+            channel: env!("RUSTC_CHANNEL"),
+            commit_hash: option_env!("RUSTC_COMMIT_HASH"),
+            commit_date: option_env!("RUSTC_COMMIT_DATE"),
+            target: env!("TARGET"),
         }
     };
 }
 
-/// Lazy static for info string
+/// Lazy static for the human-readable [`Display`](std::fmt::Display) info string
 ///
 /// # Panics
 /// Panics if `version` does not parse as semver or `rustc_version` does not parse as semver
@@ -98,7 +204,52 @@ macro_rules! raw_info {
 macro_rules! lazy_info_str {
     () => {{
         static INFO_STR: std::sync::LazyLock<String> =
-            std::sync::LazyLock::new(|| $crate::info!().to_string());
+            std::sync::LazyLock::new(|| $crate::Info::new($crate::raw_info!()).to_string());
         &*INFO_STR
     }};
 }
+
+/// Lazy static for the JSON-encoded info string, see [`Info::to_json`]
+///
+/// # Panics
+/// Panics if `version` does not parse as semver, `rustc_version` does not parse as semver,
+/// `channel` is not a recognised rustc release channel or serialization fails
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! lazy_info_json {
+    () => {{
+        static INFO_JSON: std::sync::LazyLock<String> =
+            std::sync::LazyLock::new(|| $crate::Info::new($crate::raw_info!()).to_json());
+        &*INFO_JSON
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info_with_rustc_version(rustc_version: &str) -> Info {
+        Info {
+            cargo_pkg_version: semver::Version::new(0, 1, 0),
+            git_version: Cow::Borrowed("unknown"),
+            rustc_version: rustc_version.parse().unwrap(),
+            channel: Channel::Nightly,
+            commit_hash: None,
+            commit_date: None,
+            target: Cow::Borrowed("x86_64-unknown-linux-gnu"),
+            os: os_info::Info::default(),
+        }
+    }
+
+    #[test]
+    fn rustc_satisfies_strips_pre_release_before_matching() {
+        let info = info_with_rustc_version("1.75.0-nightly");
+        assert!(info.rustc_satisfies(&semver::Version::new(1, 74, 0)));
+    }
+
+    #[test]
+    fn rustc_satisfies_rejects_msrv_newer_than_rustc() {
+        let info = info_with_rustc_version("1.75.0-nightly");
+        assert!(!info.rustc_satisfies(&semver::Version::new(1, 76, 0)));
+    }
+}